@@ -14,9 +14,12 @@
 //!
 //! You customize the graph by specifying a "node data" type `N` and an
 //! "edge data" type `E`. You can then later gain access (mutable or
-//! immutable) to these "user-data" bits. Currently, you can only add
-//! nodes or edges to the graph. You cannot remove or modify them once
-//! added. This could be changed if we have a need.
+//! immutable) to these "user-data" bits. Nodes and edges are only ever
+//! appended to the graph, never removed -- but the graph does support
+//! taking a snapshot via `start_snapshot` and later either `commit`-ing
+//! it or `rollback_to` it, so that speculative nodes and edges (e.g.
+//! constraint edges built while exploring one possibility) can be
+//! discarded wholesale.
 //!
 //! # Implementation details
 //!
@@ -31,9 +34,10 @@
 //! be indexed by the direction (see the type `Direction`).
 
 use bitvec::BitVector;
+use std::collections::VecDeque;
 use std::fmt::{Formatter, Error, Debug};
 use std::usize;
-use snapshot_vec::{SnapshotVec, SnapshotVecDelegate};
+use snapshot_vec::{SnapshotVec, SnapshotVecDelegate, Snapshot as SnapshotVecSnapshot};
 
 pub struct Graph<N,E> {
     nodes: SnapshotVec<Node<N>> ,
@@ -54,9 +58,12 @@ pub struct Edge<E> {
 
 impl<N> SnapshotVecDelegate for Node<N> {
     type Value = Node<N>;
-    type Undo = ();
+    type Undo = (NodeIndex, Direction, EdgeIndex);
 
-    fn reverse(values: &mut Vec<Node<N>>, action: ()) {}
+    fn reverse(values: &mut Vec<Node<N>>, action: (NodeIndex, Direction, EdgeIndex)) {
+        let (node, dir, first_edge) = action;
+        values[node.0].first_edge[dir.repr] = first_edge;
+    }
 }
 
 impl<N> SnapshotVecDelegate for Edge<N> {
@@ -90,6 +97,18 @@ pub const OUTGOING: Direction = Direction { repr: 0 };
 
 pub const INCOMING: Direction = Direction { repr: 1 };
 
+fn flip(direction: Direction) -> Direction {
+    if direction.repr == OUTGOING.repr { INCOMING } else { OUTGOING }
+}
+
+/// A snapshot of a `Graph`, obtained via `Graph::start_snapshot`. Pass it
+/// to `Graph::commit` to make the nodes and edges added since the
+/// snapshot permanent, or to `Graph::rollback_to` to discard them.
+pub struct Snapshot {
+    nodes_snapshot: SnapshotVecSnapshot,
+    edges_snapshot: SnapshotVecSnapshot,
+}
+
 impl NodeIndex {
     /// Returns unique id (unique with respect to the graph holding associated node).
     pub fn node_id(&self) -> usize { self.0 }
@@ -179,6 +198,13 @@ impl<N:Debug,E:Debug> Graph<N,E> {
             data: data
         });
 
+        // `first_edge` is mutated in place below rather than through
+        // `self.nodes`'s own push/set methods, so the undo action has to
+        // be recorded explicitly here in order for a rollback to restore
+        // the previous linked-list heads.
+        self.nodes.record((source, OUTGOING, source_first));
+        self.nodes.record((target, INCOMING, target_first));
+
         // adjust the firsts for each node target be the next object.
         self.nodes[source.0].first_edge[OUTGOING.repr] = idx;
         self.nodes[target.0].first_edge[INCOMING.repr] = idx;
@@ -186,6 +212,30 @@ impl<N:Debug,E:Debug> Graph<N,E> {
         return idx;
     }
 
+    ///////////////////////////////////////////////////////////////////////////
+    // Snapshots and rollback
+    //
+    // Lets speculative passes (e.g. constraint solving during region
+    // resolution) add nodes and edges and then back out of them if the
+    // speculation doesn't pan out.
+
+    pub fn start_snapshot(&mut self) -> Snapshot {
+        Snapshot {
+            nodes_snapshot: self.nodes.start_snapshot(),
+            edges_snapshot: self.edges.start_snapshot(),
+        }
+    }
+
+    pub fn rollback_to(&mut self, snapshot: Snapshot) {
+        self.edges.rollback_to(snapshot.edges_snapshot);
+        self.nodes.rollback_to(snapshot.nodes_snapshot);
+    }
+
+    pub fn commit(&mut self, snapshot: Snapshot) {
+        self.edges.commit(snapshot.edges_snapshot);
+        self.nodes.commit(snapshot.nodes_snapshot);
+    }
+
     pub fn mut_edge_data<'a>(&'a mut self, idx: EdgeIndex) -> &'a mut E {
         &mut self.edges[idx.0].data
     }
@@ -280,12 +330,355 @@ impl<N:Debug,E:Debug> Graph<N,E> {
     }
 
     pub fn depth_traverse<'a>(&'a self, start: NodeIndex) -> DepthFirstTraversal<'a, N, E>  {
+        self.depth_traverse_in_direction(start, OUTGOING)
+    }
+
+    /// Like `depth_traverse`, but `direction` selects which edges to
+    /// follow: `OUTGOING` for ordinary reachability, `INCOMING` to walk
+    /// the graph in reverse.
+    pub fn depth_traverse_in_direction<'a>(&'a self,
+                                           start: NodeIndex,
+                                           direction: Direction)
+                                           -> DepthFirstTraversal<'a, N, E> {
         DepthFirstTraversal {
             graph: self,
+            direction: direction,
             stack: vec![start],
             visited: BitVector::new(self.nodes.len()),
         }
     }
+
+    /// Breadth-first counterpart to `depth_traverse`: visits nodes
+    /// reachable from `start` by following outgoing edges, nearest
+    /// nodes first.
+    pub fn breadth_traverse<'a>(&'a self, start: NodeIndex) -> BreadthFirstTraversal<'a, N, E> {
+        self.breadth_traverse_in_direction(start, OUTGOING)
+    }
+
+    /// Like `breadth_traverse`, but `direction` selects which edges to
+    /// follow: `OUTGOING` for ordinary reachability, `INCOMING` to walk
+    /// the graph in reverse (e.g. for reverse reachability queries).
+    pub fn breadth_traverse_in_direction<'a>(&'a self,
+                                              start: NodeIndex,
+                                              direction: Direction)
+                                              -> BreadthFirstTraversal<'a, N, E> {
+        let mut queue = VecDeque::new();
+        queue.push_back(start);
+        BreadthFirstTraversal {
+            graph: self,
+            direction: direction,
+            queue: queue,
+            visited: BitVector::new(self.nodes.len()),
+        }
+    }
+
+    /// Runs a generic bitset dataflow analysis to a fixed point, reusing
+    /// the same change-tracking loop as `iterate_until_fixed_point`.
+    /// Each node gets an in-set and an out-set of `bits_per_node` bits;
+    /// every iteration, a node's in-set is recomputed as the union
+    /// ("meet") of its predecessors' out-sets, and `transfer` derives
+    /// its out-set from its in-set. `direction` picks which edges point
+    /// at predecessors: `INCOMING` for a forward analysis (predecessors
+    /// found via `incoming_edges`) or `OUTGOING` for a backward one
+    /// (predecessors found via `outgoing_edges`). The solver stops once
+    /// a full pass leaves every out-set unchanged.
+    pub fn dataflow<F>(&self,
+                       bits_per_node: usize,
+                       direction: Direction,
+                       mut transfer: F)
+                       -> DataFlowResults
+        where F: FnMut(NodeIndex, &BitVector, &mut BitVector) -> bool
+    {
+        let num_nodes = self.nodes.len();
+        let mut in_sets: Vec<BitVector> =
+            (0..num_nodes).map(|_| BitVector::new(bits_per_node)).collect();
+        let mut out_sets: Vec<BitVector> =
+            (0..num_nodes).map(|_| BitVector::new(bits_per_node)).collect();
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for i in 0..num_nodes {
+                let node = NodeIndex(i);
+
+                for (_, edge) in self.adjacent_edges(node, direction) {
+                    let pred = if direction.repr == INCOMING.repr {
+                        edge.source()
+                    } else {
+                        edge.target()
+                    };
+                    // whether this grows `in_sets[i]` doesn't by itself
+                    // warrant another pass: only a changed *out*-set
+                    // (reported by `transfer`, below) can change any
+                    // other node's in-set on the next pass.
+                    in_sets[i].insert_all(&out_sets[pred.node_id()]);
+                }
+
+                changed |= transfer(node, &in_sets[i], &mut out_sets[i]);
+            }
+        }
+
+        DataFlowResults { in_sets: in_sets, out_sets: out_sets }
+    }
+
+    ///////////////////////////////////////////////////////////////////////////
+    // Strongly connected components
+
+    /// Computes the strongly connected components of the graph using
+    /// Tarjan's algorithm. The components are returned in reverse
+    /// topological order: if there is an edge (however indirect) from a
+    /// node in component `i` to a node in component `j`, then `i >= j`
+    /// in the returned vector.
+    ///
+    /// This is implemented with an explicit work stack, rather than
+    /// native recursion, since the graphs this module is used for can be
+    /// deep enough to overflow the stack.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeIndex>> {
+        self.strongly_connected_components_in_direction(OUTGOING)
+    }
+
+    /// Like `strongly_connected_components`, but `direction` selects
+    /// which edges are followed to find each node's successors:
+    /// `OUTGOING` for the graph as given, `INCOMING` to compute the
+    /// components of its transpose instead, without copying the graph.
+    /// Reversing every edge does not change which nodes are mutually
+    /// reachable, so the set of components is identical either way;
+    /// only the reverse topological order documented on
+    /// `strongly_connected_components` is relative to `direction`.
+    pub fn strongly_connected_components_in_direction(&self, direction: Direction)
+                                                        -> Vec<Vec<NodeIndex>> {
+        struct Frame {
+            node: NodeIndex,
+            successors: Vec<NodeIndex>,
+            next: usize,
+        }
+
+        let successors_of = |node: NodeIndex| -> Vec<NodeIndex> {
+            self.adjacent_edges(node, direction)
+                .map(|(_, edge)| {
+                    if direction.repr == INCOMING.repr { edge.source() } else { edge.target() }
+                })
+                .collect()
+        };
+
+        let num_nodes = self.nodes.len();
+        let mut index = vec![usize::MAX; num_nodes];
+        let mut lowlink = vec![0; num_nodes];
+        // `BitVector` has no way to clear a bit once set, so on-stack
+        // membership (which needs to be unset as nodes are popped off
+        // into a finished component) is tracked with a plain `Vec<bool>`
+        // instead.
+        let mut on_stack = vec![false; num_nodes];
+        let mut node_stack = Vec::new();
+        let mut components = Vec::new();
+        let mut counter = 0;
+
+        for start in 0..num_nodes {
+            let start = NodeIndex(start);
+            if index[start.node_id()] != usize::MAX {
+                // already visited as part of an earlier node's DFS
+                continue;
+            }
+
+            let mut work = vec![Frame {
+                node: start,
+                successors: successors_of(start),
+                next: 0,
+            }];
+            index[start.node_id()] = counter;
+            lowlink[start.node_id()] = counter;
+            counter += 1;
+            node_stack.push(start);
+            on_stack[start.node_id()] = true;
+
+            while let Some(frame) = work.last_mut() {
+                let v = frame.node;
+
+                if frame.next < frame.successors.len() {
+                    let w = frame.successors[frame.next];
+                    frame.next += 1;
+
+                    if index[w.node_id()] == usize::MAX {
+                        index[w.node_id()] = counter;
+                        lowlink[w.node_id()] = counter;
+                        counter += 1;
+                        node_stack.push(w);
+                        on_stack[w.node_id()] = true;
+                        work.push(Frame {
+                            node: w,
+                            successors: successors_of(w),
+                            next: 0,
+                        });
+                    } else if on_stack[w.node_id()] {
+                        lowlink[v.node_id()] = ::std::cmp::min(lowlink[v.node_id()], index[w.node_id()]);
+                    }
+
+                    continue;
+                }
+
+                // all successors of `v` have been visited; fold its
+                // lowlink into its parent's before popping it
+                work.pop();
+                if let Some(parent) = work.last() {
+                    lowlink[parent.node.node_id()] =
+                        ::std::cmp::min(lowlink[parent.node.node_id()], lowlink[v.node_id()]);
+                }
+
+                if lowlink[v.node_id()] == index[v.node_id()] {
+                    let mut component = Vec::new();
+                    loop {
+                        let w = node_stack.pop().unwrap();
+                        on_stack[w.node_id()] = false;
+                        component.push(w);
+                        if w.node_id() == v.node_id() {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+    /// Returns a transpose view of this graph, with `OUTGOING` and
+    /// `INCOMING` swapped in its adjacency queries and traversals
+    /// (`outgoing_edges`, `successor_nodes`, `depth_traverse`, etc.).
+    /// Costs no allocation, since both the incoming and outgoing linked
+    /// lists already exist on every node; region resolution and
+    /// post-dominance computations can use it instead of manually
+    /// calling `incoming_edges` everywhere.
+    ///
+    /// `Reversed` only offers this adjacency/traversal surface, not the
+    /// whole-graph algorithms built on top of it -- `strongly_connected_components`
+    /// and `dataflow` remain inherent `Graph` methods and can't take a
+    /// `Reversed` in their place. Both already have their own
+    /// `direction: Direction` entry points for running on the transpose
+    /// without copying the graph
+    /// (`strongly_connected_components_in_direction`, `dataflow` itself),
+    /// so reach for those instead of wrapping the graph first.
+    pub fn reversed<'a>(&'a self) -> Reversed<'a, N, E> {
+        Reversed { graph: self }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+// Reverse/transpose view
+
+pub struct Reversed<'g, N:'g, E:'g> {
+    graph: &'g Graph<N, E>,
+}
+
+impl<'g, N:Debug, E:Debug> Reversed<'g, N, E> {
+    pub fn outgoing_edges(&self, source: NodeIndex) -> AdjacentEdges<'g, N, E> {
+        self.graph.adjacent_edges(source, INCOMING)
+    }
+
+    pub fn incoming_edges(&self, source: NodeIndex) -> AdjacentEdges<'g, N, E> {
+        self.graph.adjacent_edges(source, OUTGOING)
+    }
+
+    pub fn adjacent_edges(&self, source: NodeIndex, direction: Direction) -> AdjacentEdges<'g, N, E> {
+        self.graph.adjacent_edges(source, flip(direction))
+    }
+
+    pub fn successor_nodes(&self, source: NodeIndex) -> Vec<NodeIndex> {
+        self.graph.predecessor_nodes(source)
+    }
+
+    pub fn predecessor_nodes(&self, target: NodeIndex) -> Vec<NodeIndex> {
+        self.graph.successor_nodes(target)
+    }
+
+    pub fn depth_traverse(&self, start: NodeIndex) -> DepthFirstTraversal<'g, N, E> {
+        self.graph.depth_traverse_in_direction(start, INCOMING)
+    }
+
+    pub fn breadth_traverse(&self, start: NodeIndex) -> BreadthFirstTraversal<'g, N, E> {
+        self.graph.breadth_traverse_in_direction(start, INCOMING)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+// Generic bitset dataflow
+
+/// The result of running a `Graph::dataflow` computation to a fixed
+/// point: the final in-set and out-set computed for each node.
+pub struct DataFlowResults {
+    in_sets: Vec<BitVector>,
+    out_sets: Vec<BitVector>,
+}
+
+impl DataFlowResults {
+    pub fn in_set(&self, node: NodeIndex) -> &BitVector {
+        &self.in_sets[node.node_id()]
+    }
+
+    pub fn out_set(&self, node: NodeIndex) -> &BitVector {
+        &self.out_sets[node.node_id()]
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+// Construction from a text adjacency matrix
+
+impl Graph<(), ()> {
+    /// Parses a whitespace-separated 0/1 adjacency matrix, the format
+    /// used by many graph benchmarking suites, into a `Graph<(), ()>`.
+    /// Row `r`, column `c` being `1` means there is a directed edge from
+    /// node `r` to node `c`; one node is created per row, before any
+    /// edge is added, so that the resulting node count equals the
+    /// matrix's dimension. Blank lines
+    /// are ignored. Returns a descriptive error if an entry is not `0`
+    /// or `1`, or if the matrix is not square.
+    pub fn from_adjacency_matrix(text: &str) -> Result<Graph<(), ()>, String> {
+        let mut rows = Vec::new();
+        for line in text.trim().lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut row = Vec::new();
+            for entry in line.split_whitespace() {
+                match entry {
+                    "0" => row.push(false),
+                    "1" => row.push(true),
+                    other => {
+                        return Err(format!("invalid adjacency matrix entry {:?}, expected 0 or 1",
+                                            other));
+                    }
+                }
+            }
+            rows.push(row);
+        }
+
+        let n = rows.len();
+        for (r, row) in rows.iter().enumerate() {
+            if row.len() != n {
+                return Err(format!("adjacency matrix must be square: row {} has {} entries, \
+                                     but there are {} rows",
+                                    r, row.len(), n));
+            }
+        }
+
+        let mut graph = Graph::new();
+        for _ in 0..n {
+            graph.add_node(());
+        }
+
+        for (r, row) in rows.iter().enumerate() {
+            for (c, &bit) in row.iter().enumerate() {
+                if bit {
+                    graph.add_edge(NodeIndex(r), NodeIndex(c), ());
+                }
+            }
+        }
+
+        Ok(graph)
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////
@@ -314,6 +707,7 @@ impl<'g, N:Debug, E:Debug> Iterator for AdjacentEdges<'g, N, E> {
 
 pub struct DepthFirstTraversal<'g, N:'g, E:'g> {
     graph: &'g Graph<N, E>,
+    direction: Direction,
     stack: Vec<NodeIndex>,
     visited: BitVector
 }
@@ -327,9 +721,48 @@ impl<'g, N:Debug, E:Debug> Iterator for DepthFirstTraversal<'g, N, E> {
                 continue;
             }
 
-            for (_, edge) in self.graph.outgoing_edges(idx) {
-                if !self.visited.contains(edge.target().node_id()) {
-                    self.stack.push(edge.target());
+            for (_, edge) in self.graph.adjacent_edges(idx, self.direction) {
+                let next = if self.direction.repr == OUTGOING.repr {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                if !self.visited.contains(next.node_id()) {
+                    self.stack.push(next);
+                }
+            }
+
+            return Some(self.graph.node_data(idx));
+        }
+
+        return None;
+    }
+}
+
+pub struct BreadthFirstTraversal<'g, N:'g, E:'g> {
+    graph: &'g Graph<N, E>,
+    direction: Direction,
+    queue: VecDeque<NodeIndex>,
+    visited: BitVector
+}
+
+impl<'g, N:Debug, E:Debug> Iterator for BreadthFirstTraversal<'g, N, E> {
+    type Item = &'g N;
+
+    fn next(&mut self) -> Option<&'g N> {
+        while let Some(idx) = self.queue.pop_front() {
+            if !self.visited.insert(idx.node_id()) {
+                continue;
+            }
+
+            for (_, edge) in self.graph.adjacent_edges(idx, self.direction) {
+                let next = if self.direction.repr == OUTGOING.repr {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                if !self.visited.contains(next.node_id()) {
+                    self.queue.push_back(next);
                 }
             }
 
@@ -494,4 +927,225 @@ mod test {
                             &[("BD", "B")],
                             &[("DE", "E")]);
     }
+
+    #[test]
+    fn from_adjacency_matrix() {
+        let graph = Graph::<(), ()>::from_adjacency_matrix("0 1 0\n\
+                                                             0 0 1\n\
+                                                             0 0 0\n").unwrap();
+        assert_eq!(graph.all_nodes().len(), 3);
+        assert_eq!(graph.successor_nodes(NodeIndex(0)), vec![NodeIndex(1)]);
+        assert_eq!(graph.successor_nodes(NodeIndex(1)), vec![NodeIndex(2)]);
+        assert_eq!(graph.successor_nodes(NodeIndex(2)), vec![]);
+    }
+
+    #[test]
+    fn from_adjacency_matrix_rejects_bad_entry() {
+        assert!(Graph::<(), ()>::from_adjacency_matrix("0 2\n1 0\n").is_err());
+    }
+
+    #[test]
+    fn from_adjacency_matrix_rejects_non_square() {
+        assert!(Graph::<(), ()>::from_adjacency_matrix("0 1 0\n1 0\n").is_err());
+    }
+
+    #[test]
+    fn rollback_to_restores_first_edge() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("A");
+        let b = graph.add_node("B");
+        let c = graph.add_node("C");
+
+        graph.add_edge(a, b, "AB");
+
+        let successors_a = graph.successor_nodes(a);
+        let predecessors_b = graph.predecessor_nodes(b);
+        let first_out_a = graph.first_adjacent(a, OUTGOING);
+        let first_in_b = graph.first_adjacent(b, INCOMING);
+
+        let snapshot = graph.start_snapshot();
+        graph.add_edge(a, c, "AC");
+        graph.add_edge(c, b, "CB");
+
+        // sanity check: the speculative edges really did rewrite the
+        // linked-list heads before we roll them back
+        assert_eq!(graph.successor_nodes(a), vec![c, b]);
+        assert_eq!(graph.predecessor_nodes(b), vec![c, a]);
+
+        graph.rollback_to(snapshot);
+
+        assert_eq!(graph.successor_nodes(a), successors_a);
+        assert_eq!(graph.predecessor_nodes(b), predecessors_b);
+        assert_eq!(graph.first_adjacent(a, OUTGOING), first_out_a);
+        assert_eq!(graph.first_adjacent(b, INCOMING), first_in_b);
+        assert_eq!(graph.all_edges().len(), 1);
+    }
+
+    #[test]
+    fn commit_keeps_changes() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("A");
+        let b = graph.add_node("B");
+
+        let snapshot = graph.start_snapshot();
+        graph.add_edge(a, b, "AB");
+        graph.commit(snapshot);
+
+        assert_eq!(graph.successor_nodes(a), vec![b]);
+        assert_eq!(graph.predecessor_nodes(b), vec![a]);
+        assert_eq!(graph.all_edges().len(), 1);
+    }
+
+    #[test]
+    fn dataflow_computes_transitive_reachability() {
+        let graph = create_graph();
+        // indices, per create_graph: A=0, B=1, C=2, D=3, E=4, F=5
+
+        fn transfer(node: NodeIndex, in_set: &BitVector, out_set: &mut BitVector) -> bool {
+            let mut changed = out_set.insert_all(in_set);
+            if out_set.insert(node.node_id()) {
+                changed = true;
+            }
+            changed
+        }
+
+        // forward analysis (`INCOMING`): each node's out-set accumulates
+        // the set of nodes that can reach it.
+        let ancestors = graph.dataflow(6, INCOMING, transfer);
+
+        // D is reachable from A, B, and F (and itself), but not from C
+        // or E: nothing flows from them to D.
+        for &i in &[0, 1, 5, 3] {
+            assert!(ancestors.out_set(NodeIndex(3)).contains(i));
+        }
+        assert!(!ancestors.out_set(NodeIndex(3)).contains(2));
+        assert!(!ancestors.out_set(NodeIndex(3)).contains(4));
+
+        // A has no incoming edges, so only itself reaches it.
+        for i in 0..6 {
+            assert_eq!(ancestors.out_set(NodeIndex(0)).contains(i), i == 0);
+        }
+
+        // backward analysis (`OUTGOING`): each node's out-set
+        // accumulates the set of nodes reachable from it.
+        let descendants = graph.dataflow(6, OUTGOING, transfer);
+
+        // everything downstream of A (B, C, D, E), plus A itself, is
+        // reachable; F is not.
+        for &i in &[0, 1, 2, 3, 4] {
+            assert!(descendants.out_set(NodeIndex(0)).contains(i));
+        }
+        assert!(!descendants.out_set(NodeIndex(0)).contains(5));
+    }
+
+    #[test]
+    fn breadth_traverse_is_level_order() {
+        let graph = create_graph();
+        let a = NodeIndex(0);
+
+        let order: Vec<&str> = graph.breadth_traverse(a).cloned().collect();
+
+        // A -> B at depth 1; B -> D, C at depth 2 (D before C: the most
+        // recently added outgoing edge from B, BD, heads its list); then
+        // D -> E at depth 3. F is never reached from A.
+        assert_eq!(order, vec!["A", "B", "D", "C", "E"]);
+    }
+
+    #[test]
+    fn breadth_traverse_in_direction_incoming_is_reverse_reachability() {
+        let graph = create_graph();
+        let c = NodeIndex(2);
+
+        let order: Vec<&str> =
+            graph.breadth_traverse_in_direction(c, INCOMING).cloned().collect();
+
+        // walking incoming edges from C reaches every node that can
+        // reach C, nearest predecessors first.
+        assert_eq!(order, vec!["C", "E", "B", "D", "F", "A"]);
+    }
+
+    #[test]
+    fn reversed_swaps_successors_and_predecessors() {
+        let graph = create_graph();
+        let reversed = graph.reversed();
+        let b = NodeIndex(1);
+
+        assert_eq!(reversed.successor_nodes(b), graph.predecessor_nodes(b));
+        assert_eq!(reversed.predecessor_nodes(b), graph.successor_nodes(b));
+    }
+
+    #[test]
+    fn reversed_traversals_match_incoming_direction() {
+        let graph = create_graph();
+        let reversed = graph.reversed();
+        let c = NodeIndex(2);
+
+        let via_reversed: Vec<&str> = reversed.breadth_traverse(c).cloned().collect();
+        let via_direction: Vec<&str> =
+            graph.breadth_traverse_in_direction(c, INCOMING).cloned().collect();
+        assert_eq!(via_reversed, via_direction);
+
+        let via_reversed: Vec<&str> = reversed.depth_traverse(c).cloned().collect();
+        let via_direction: Vec<&str> =
+            graph.depth_traverse_in_direction(c, INCOMING).cloned().collect();
+        assert_eq!(via_reversed, via_direction);
+    }
+
+    #[test]
+    fn strongly_connected_components_in_direction_same_membership() {
+        // 0 -> 1 -> 2 -> 0 is a cycle; 2 -> 3 hangs a trivial, singleton
+        // component off of it.
+        let graph = Graph::<(), ()>::from_adjacency_matrix("0 1 0 0\n\
+                                                             0 0 1 0\n\
+                                                             1 0 0 1\n\
+                                                             0 0 0 0\n").unwrap();
+
+        let forward = graph.strongly_connected_components();
+        let backward = graph.strongly_connected_components_in_direction(INCOMING);
+
+        let as_sets = |components: &Vec<Vec<NodeIndex>>| {
+            let mut sets: Vec<Vec<NodeIndex>> = components.iter().map(|c| {
+                let mut c = c.clone();
+                c.sort_by_key(|n| n.node_id());
+                c
+            }).collect();
+            sets.sort_by_key(|c| c[0].node_id());
+            sets
+        };
+
+        // reversing every edge doesn't change which nodes are mutually
+        // reachable, just the order components come out in.
+        assert_eq!(as_sets(&forward), as_sets(&backward));
+    }
+
+    #[test]
+    fn strongly_connected_components() {
+        // 0 -> 1 -> 2 -> 0 is a cycle; 2 -> 3 hangs a trivial, singleton
+        // component off of it.
+        let graph = Graph::<(), ()>::from_adjacency_matrix("0 1 0 0\n\
+                                                             0 0 1 0\n\
+                                                             1 0 0 1\n\
+                                                             0 0 0 0\n").unwrap();
+
+        let components = graph.strongly_connected_components();
+        assert_eq!(components.len(), 2);
+
+        let index_of = |node: NodeIndex| {
+            components.iter().position(|c| c.contains(&node)).unwrap()
+        };
+
+        let cycle = index_of(NodeIndex(0));
+        assert_eq!(index_of(NodeIndex(1)), cycle);
+        assert_eq!(index_of(NodeIndex(2)), cycle);
+        assert_eq!(components[cycle].len(), 3);
+
+        let trivial = index_of(NodeIndex(3));
+        assert_ne!(trivial, cycle);
+        assert_eq!(components[trivial], vec![NodeIndex(3)]);
+
+        // there is an edge from the cycle into the trivial component, so
+        // the documented reverse-topological invariant requires
+        // `cycle >= trivial`.
+        assert!(cycle >= trivial);
+    }
 }